@@ -5,23 +5,379 @@ use nom::{
     branch::alt,
     bytes::complete::{is_not, tag, take_while_m_n},
     character::complete::{char, multispace0},
-    combinator::{map, recognize},
-    multi::{many0, separated_list0},
+    combinator::map,
+    error::ErrorKind,
+    multi::fold_many0,
     number::complete::recognize_float,
     sequence::{delimited, preceded, separated_pair},
 };
 
+/// The reason a JSON parse failed, independent of where in the input it happened.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonParseErrorKind {
+    UnexpectedEndOfInput,
+    ExpectedToken(char),
+    UnexpectedToken(char),
+    ExpectedObjectKey,
+    ExpectedDigit,
+    InvalidEscape,
+    InvalidUnicodeEscape,
+}
+
+impl Display for JsonParseErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonParseErrorKind::UnexpectedEndOfInput => write!(f, "unexpected end of input"),
+            JsonParseErrorKind::ExpectedToken(c) => write!(f, "expected '{}'", c),
+            JsonParseErrorKind::UnexpectedToken(c) => write!(f, "unexpected character '{}'", c),
+            JsonParseErrorKind::ExpectedObjectKey => write!(f, "expected object key"),
+            JsonParseErrorKind::ExpectedDigit => write!(f, "expected digit"),
+            JsonParseErrorKind::InvalidEscape => write!(f, "invalid escape sequence"),
+            JsonParseErrorKind::InvalidUnicodeEscape => write!(f, "invalid unicode escape"),
+        }
+    }
+}
+
+/// A JSON parse error with the byte offset, line and column at which it occurred.
+///
+/// Line and column are 1-based and derived by counting newlines in the input
+/// up to `offset`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonParseError {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub kind: JsonParseErrorKind,
+}
+
+impl JsonParseError {
+    fn at(input: &str, offset: usize, kind: JsonParseErrorKind) -> Self {
+        let (line, column) = line_col(input, offset);
+        JsonParseError {
+            offset,
+            line,
+            column,
+            kind,
+        }
+    }
+}
+
+impl Display for JsonParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "error at line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl std::error::Error for JsonParseError {}
+
+/// Counts newlines in `input[..offset]` to derive a 1-based (line, column) pair.
+fn line_col(input: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for c in input[..offset].chars() {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+/// The `nom` error type threaded through every parser in this module: it
+/// remembers where a failure happened and why, so the top-level `parse_json`
+/// can turn it into a [`JsonParseError`] with line/column information.
+///
+/// Public only because it appears in the signature of the `pub fn
+/// parse_json_*` parsers; callers outside this crate should go through
+/// [`parse_json`] rather than constructing or inspecting it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonError<'a> {
+    input: &'a str,
+    kind: JsonParseErrorKind,
+}
+
+impl<'a> JsonError<'a> {
+    fn new(input: &'a str, kind: JsonParseErrorKind) -> Self {
+        JsonError { input, kind }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for JsonError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        let reason = match kind {
+            ErrorKind::Digit | ErrorKind::Float => JsonParseErrorKind::ExpectedDigit,
+            _ => match input.chars().next() {
+                Some(c) => JsonParseErrorKind::UnexpectedToken(c),
+                None => JsonParseErrorKind::UnexpectedEndOfInput,
+            },
+        };
+        JsonError::new(input, reason)
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn or(self, other: Self) -> Self {
+        // Prefer whichever branch consumed more input before failing: it is
+        // the more specific (and usually more useful) diagnosis.
+        if other.input.len() <= self.input.len() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+/// Runs `parser`, replacing any recoverable error (but not a hard `Failure`)
+/// with `kind`. Used to turn a generic combinator failure, like a missing
+/// `char(':')`, into a specific, user-facing diagnosis.
+fn expect<'a, O>(
+    kind: JsonParseErrorKind,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O, JsonError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O, JsonError<'a>> {
+    move |input: &'a str| {
+        parser(input).map_err(|e| match e {
+            nom::Err::Error(_) => nom::Err::Error(JsonError::new(input, kind.clone())),
+            other => other,
+        })
+    }
+}
+
+/// Like `nom::multi::separated_list0`, but once a separator has matched, a
+/// failure in the following element is promoted to a hard `Failure` instead
+/// of being swallowed as "the list ends here". Without this, a malformed
+/// element after a comma (e.g. a missing `:` in `{"a": 1, "b" 2}`) gets
+/// blamed on the closing bracket instead of on itself.
+fn separated_list_cut<'a, O, S>(
+    mut separator: impl FnMut(&'a str) -> IResult<&'a str, S, JsonError<'a>>,
+    mut element: impl FnMut(&'a str) -> IResult<&'a str, O, JsonError<'a>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>, JsonError<'a>> {
+    move |input: &'a str| {
+        let mut items = Vec::new();
+        let (mut rest, first) = match element(input) {
+            Ok(ok) => ok,
+            Err(nom::Err::Error(_)) => return Ok((input, items)),
+            Err(e) => return Err(e),
+        };
+        items.push(first);
+        loop {
+            match separator(rest) {
+                Err(_) => break,
+                Ok((after_sep, _)) => match element(after_sep) {
+                    Ok((after_elem, value)) => {
+                        items.push(value);
+                        rest = after_elem;
+                    }
+                    Err(nom::Err::Error(e)) => return Err(nom::Err::Failure(e)),
+                    Err(e) => return Err(e),
+                },
+            }
+        }
+        Ok((rest, items))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum JSONObject {
     Null,
     Bool(bool),
+    Int(i64),
+    UInt(u64),
     Number(f64),
     String(String),
     Array(Vec<JSONObject>),
     Map(Vec<(String, JSONObject)>),
 }
 
-pub fn parse_json_null(input: &str) -> IResult<&str, JSONObject> {
+/// The error returned by the `TryFrom<JSONObject>` impls when the value is
+/// not of the expected variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeError {
+    pub expected: &'static str,
+}
+
+impl Display for TypeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {}", self.expected)
+    }
+}
+
+impl std::error::Error for TypeError {}
+
+impl TryFrom<JSONObject> for f64 {
+    type Error = TypeError;
+
+    fn try_from(value: JSONObject) -> Result<Self, Self::Error> {
+        match value {
+            JSONObject::Number(n) => Ok(n),
+            JSONObject::Int(n) => Ok(n as f64),
+            JSONObject::UInt(n) => Ok(n as f64),
+            _ => Err(TypeError { expected: "number" }),
+        }
+    }
+}
+
+impl TryFrom<JSONObject> for i64 {
+    type Error = TypeError;
+
+    fn try_from(value: JSONObject) -> Result<Self, Self::Error> {
+        match value {
+            JSONObject::Int(n) => Ok(n),
+            _ => Err(TypeError { expected: "integer" }),
+        }
+    }
+}
+
+impl TryFrom<JSONObject> for u64 {
+    type Error = TypeError;
+
+    fn try_from(value: JSONObject) -> Result<Self, Self::Error> {
+        match value {
+            JSONObject::UInt(n) => Ok(n),
+            JSONObject::Int(n) if n >= 0 => Ok(n as u64),
+            _ => Err(TypeError {
+                expected: "unsigned integer",
+            }),
+        }
+    }
+}
+
+impl TryFrom<JSONObject> for String {
+    type Error = TypeError;
+
+    fn try_from(value: JSONObject) -> Result<Self, Self::Error> {
+        match value {
+            JSONObject::String(s) => Ok(s),
+            _ => Err(TypeError { expected: "string" }),
+        }
+    }
+}
+
+impl TryFrom<JSONObject> for bool {
+    type Error = TypeError;
+
+    fn try_from(value: JSONObject) -> Result<Self, Self::Error> {
+        match value {
+            JSONObject::Bool(b) => Ok(b),
+            _ => Err(TypeError { expected: "bool" }),
+        }
+    }
+}
+
+impl TryFrom<JSONObject> for Vec<JSONObject> {
+    type Error = TypeError;
+
+    fn try_from(value: JSONObject) -> Result<Self, Self::Error> {
+        match value {
+            JSONObject::Array(arr) => Ok(arr),
+            _ => Err(TypeError { expected: "array" }),
+        }
+    }
+}
+
+impl JSONObject {
+    /// Returns the value as an `f64`, or `None` if `self` is not numeric.
+    /// `Int`/`UInt` are widened with `as`, which can lose precision for
+    /// magnitudes beyond `f64`'s 53-bit mantissa.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JSONObject::Number(n) => Some(*n),
+            JSONObject::Int(n) => Some(*n as f64),
+            JSONObject::UInt(n) => Some(*n as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner integer, or `None` if `self` is not an `Int`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            JSONObject::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `u64`, or `None` if `self` is not a
+    /// non-negative integer. Non-negative `Int` values (the common case,
+    /// since `number_from_token` only stores `UInt` above `i64::MAX`) are
+    /// widened with `as`.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            JSONObject::UInt(n) => Some(*n),
+            JSONObject::Int(n) if *n >= 0 => Some(*n as u64),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner string slice, or `None` if `self` is not a `String`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JSONObject::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner bool, or `None` if `self` is not a `Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JSONObject::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner elements, or `None` if `self` is not an `Array`.
+    pub fn as_array(&self) -> Option<&[JSONObject]> {
+        match self {
+            JSONObject::Array(arr) => Some(arr.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `self` is `Null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, JSONObject::Null)
+    }
+
+    /// Looks up `key` in a `Map`, returning the first matching value.
+    /// Returns `None` for non-maps or a missing key. `Map` is a
+    /// `Vec<(String, JSONObject)>`, so this is a linear scan.
+    pub fn get(&self, key: &str) -> Option<&JSONObject> {
+        match self {
+            JSONObject::Map(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Like [`JSONObject::get`], but returns every value stored under `key`
+    /// instead of just the first, for maps with duplicated keys.
+    pub fn get_all(&self, key: &str) -> Vec<&JSONObject> {
+        match self {
+            JSONObject::Map(entries) => entries
+                .iter()
+                .filter(|(k, _)| k == key)
+                .map(|(_, v)| v)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Looks up index `i` in an `Array`. Returns `None` for non-arrays or an
+    /// out-of-bounds index.
+    pub fn index(&self, i: usize) -> Option<&JSONObject> {
+        match self {
+            JSONObject::Array(arr) => arr.get(i),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_json_null(input: &str) -> IResult<&str, JSONObject, JsonError<'_>> {
     let mut parser = tag("null");
     let result = parser.parse(input);
     match result {
@@ -30,7 +386,7 @@ pub fn parse_json_null(input: &str) -> IResult<&str, JSONObject> {
     }
 }
 
-pub fn parse_json_bool(input: &str) -> IResult<&str, JSONObject> {
+pub fn parse_json_bool(input: &str) -> IResult<&str, JSONObject, JsonError<'_>> {
     let mut parser = alt((tag("true"), tag("false")));
     let result = parser.parse(input);
     match result {
@@ -39,52 +395,125 @@ pub fn parse_json_bool(input: &str) -> IResult<&str, JSONObject> {
     }
 }
 
-pub fn parse_json_number(input: &str) -> IResult<&str, JSONObject> {
+/// Converts the span matched by `recognize_float` into the narrowest variant
+/// that can represent it without losing precision: a token with no `.`, `e`
+/// or `E` is an integer, tried as `i64` then `u64` before falling back to
+/// `f64`.
+fn number_from_token(token: &str) -> JSONObject {
+    if !token.contains(['.', 'e', 'E']) {
+        if let Ok(i) = token.parse::<i64>() {
+            return JSONObject::Int(i);
+        }
+        if let Ok(u) = token.parse::<u64>() {
+            return JSONObject::UInt(u);
+        }
+    }
+    JSONObject::Number(token.parse().expect("Error during Float Parsing"))
+}
+
+pub fn parse_json_number(input: &str) -> IResult<&str, JSONObject, JsonError<'_>> {
     let mut parser = |x| recognize_float(x);
     let result = parser.parse(input);
     match result {
-        Ok((rest, parsed)) => Ok((
-            rest,
-            JSONObject::Number(parsed.parse().expect("Error during Float Parsing")),
-        )),
+        Ok((rest, parsed)) => Ok((rest, number_from_token(parsed))),
         Err(e) => Err(e),
     }
 }
-pub fn parse_json_string(input: &str) -> IResult<&str, JSONObject> {
-    // Parser for a single escape sequence (does not handle unicode)
-    let parse_escape = preceded(
-        char('\\'),
-        alt((
-            char('"'),
-            char('\\'),
-            char('/'),
-            char('b'),
-            char('f'),
-            char('n'),
-            char('r'),
-            char('t'),
-            // Unicode escapes (\uXXXX)
-            map(
-                preceded(
-                    char('u'),
-                    take_while_m_n(4, 4, |c: char| c.is_ascii_hexdigit()),
-                ),
-                |_| 'u',
-            ),
-        )),
-    );
+/// A piece of a JSON string literal: either a run of literal source
+/// characters, or a single character produced by decoding an escape.
+enum StringFragment<'a> {
+    Literal(&'a str),
+    EscapedChar(char),
+}
+
+/// Parses exactly four hex digits into the `u16` code unit they spell out.
+fn parse_hex4(input: &str) -> IResult<&str, u16, JsonError<'_>> {
+    map(take_while_m_n(4, 4, |c: char| c.is_ascii_hexdigit()), |hex| {
+        u16::from_str_radix(hex, 16).expect("take_while_m_n validated ascii hex digits")
+    })
+    .parse(input)
+}
+
+/// Parses a `\uXXXX` escape (with the leading `u` already expected here),
+/// combining a high/low surrogate pair into a single scalar value and
+/// rejecting a lone surrogate.
+fn parse_unicode_escape(input: &str) -> IResult<&str, char, JsonError<'_>> {
+    let (input, high) = preceded(char('u'), parse_hex4).parse(input)?;
+
+    if (0xDC00..0xE000).contains(&high) {
+        // A low surrogate with no preceding high surrogate.
+        return Err(nom::Err::Failure(JsonError::new(
+            input,
+            JsonParseErrorKind::InvalidUnicodeEscape,
+        )));
+    }
+
+    if (0xD800..0xDC00).contains(&high) {
+        let (input, low) = preceded(tag("\\u"), parse_hex4).parse(input).map_err(|e| {
+            e.map(|_| JsonError::new(input, JsonParseErrorKind::InvalidUnicodeEscape))
+        })?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(nom::Err::Failure(JsonError::new(
+                input,
+                JsonParseErrorKind::InvalidUnicodeEscape,
+            )));
+        }
+        let scalar = 0x10000 + (((high - 0xD800) as u32) << 10) + (low - 0xDC00) as u32;
+        let c = char::from_u32(scalar).expect("combined surrogate pair is always a valid scalar");
+        return Ok((input, c));
+    }
 
-    // Parser for a single string fragment (either normal or escaped)
-    let parse_fragment = alt((
-        is_not("\\\""), // normal string chars except backslash and quote
-        recognize(parse_escape),
-    ));
+    Ok((
+        input,
+        char::from_u32(high as u32).expect("non-surrogate BMP code unit is always a valid char"),
+    ))
+}
 
-    let parse_string_content = map(many0(parse_fragment), |fragments: Vec<&str>| {
-        fragments.concat()
+/// Parses a single escape sequence, leading backslash included, into the character it decodes to.
+fn parse_escaped_char(input: &str) -> IResult<&str, char, JsonError<'_>> {
+    let (rest, _) = char('\\').parse(input)?;
+    alt((
+        map(char('"'), |_| '"'),
+        map(char('\\'), |_| '\\'),
+        map(char('/'), |_| '/'),
+        map(char('b'), |_| '\u{8}'),
+        map(char('f'), |_| '\u{c}'),
+        map(char('n'), |_| '\n'),
+        map(char('r'), |_| '\r'),
+        map(char('t'), |_| '\t'),
+        parse_unicode_escape,
+    ))
+    .parse(rest)
+    .map_err(|e| match e {
+        nom::Err::Error(_) => {
+            nom::Err::Error(JsonError::new(rest, JsonParseErrorKind::InvalidEscape))
+        }
+        other => other,
+    })
+}
+
+fn parse_string_fragment(input: &str) -> IResult<&str, StringFragment<'_>, JsonError<'_>> {
+    alt((
+        map(is_not("\\\""), StringFragment::Literal),
+        map(parse_escaped_char, StringFragment::EscapedChar),
+    ))
+    .parse(input)
+}
+
+pub fn parse_json_string(input: &str) -> IResult<&str, JSONObject, JsonError<'_>> {
+    let parse_string_content = fold_many0(parse_string_fragment, String::new, |mut acc, frag| {
+        match frag {
+            StringFragment::Literal(s) => acc.push_str(s),
+            StringFragment::EscapedChar(c) => acc.push(c),
+        }
+        acc
     });
 
-    let mut parser = delimited(tag("\""), parse_string_content, tag("\""));
+    let mut parser = delimited(
+        expect(JsonParseErrorKind::ExpectedToken('"'), char('"')),
+        parse_string_content,
+        expect(JsonParseErrorKind::ExpectedToken('"'), char('"')),
+    );
     let result = parser.parse(input);
     match result {
         Ok((rest, parsed)) => Ok((rest, JSONObject::String(parsed))),
@@ -92,15 +521,19 @@ pub fn parse_json_string(input: &str) -> IResult<&str, JSONObject> {
     }
 }
 
-pub fn parse_json_array(input: &str) -> IResult<&str, JSONObject> {
-    let elements = separated_list0(
+pub fn parse_json_array(input: &str) -> IResult<&str, JSONObject, JsonError<'_>> {
+    let elements = separated_list_cut(
         delimited(multispace0, char(','), multispace0),
         parse_json_value,
     );
     let mut array_parser = delimited(
         delimited(multispace0, char('['), multispace0),
         elements,
-        delimited(multispace0, char(']'), multispace0),
+        delimited(
+            multispace0,
+            expect(JsonParseErrorKind::ExpectedToken(']'), char(']')),
+            multispace0,
+        ),
     );
 
     let result = array_parser.parse(input);
@@ -110,19 +543,31 @@ pub fn parse_json_array(input: &str) -> IResult<&str, JSONObject> {
     }
 }
 
-fn parse_json_map(input: &str) -> IResult<&str, JSONObject> {
+fn parse_json_map(input: &str) -> IResult<&str, JSONObject, JsonError<'_>> {
     let key_value = separated_pair(
-        delimited(multispace0, parse_json_string, multispace0),
-        delimited(multispace0, char(':'), multispace0),
+        delimited(
+            multispace0,
+            expect(JsonParseErrorKind::ExpectedObjectKey, parse_json_string),
+            multispace0,
+        ),
+        delimited(
+            multispace0,
+            expect(JsonParseErrorKind::ExpectedToken(':'), char(':')),
+            multispace0,
+        ),
         parse_json_value,
     );
 
-    let map_contents = separated_list0(delimited(multispace0, char(','), multispace0), key_value);
+    let map_contents = separated_list_cut(delimited(multispace0, char(','), multispace0), key_value);
 
     let mut full_parser = delimited(
         delimited(multispace0, char('{'), multispace0),
         map_contents,
-        delimited(multispace0, char('}'), multispace0),
+        delimited(
+            multispace0,
+            expect(JsonParseErrorKind::ExpectedToken('}'), char('}')),
+            multispace0,
+        ),
     );
 
     let result = full_parser.parse(input);
@@ -134,9 +579,9 @@ fn parse_json_map(input: &str) -> IResult<&str, JSONObject> {
                 if let JSONObject::String(key) = k {
                     vec.push((key, v));
                 } else {
-                    return Err(nom::Err::Error(nom::error::Error::new(
+                    return Err(nom::Err::Error(JsonError::new(
                         input,
-                        nom::error::ErrorKind::Tag,
+                        JsonParseErrorKind::ExpectedObjectKey,
                     )));
                 }
             }
@@ -146,7 +591,7 @@ fn parse_json_map(input: &str) -> IResult<&str, JSONObject> {
     }
 }
 
-pub fn parse_json_value(input: &str) -> IResult<&str, JSONObject> {
+pub fn parse_json_value(input: &str) -> IResult<&str, JSONObject, JsonError<'_>> {
     delimited(
         multispace0,
         alt((
@@ -162,13 +607,102 @@ pub fn parse_json_value(input: &str) -> IResult<&str, JSONObject> {
     .parse(input)
 }
 
+/// Parses a complete JSON document, converting any parser failure into a
+/// [`JsonParseError`] with a byte offset and derived line/column.
+pub fn parse_json(input: &str) -> Result<JSONObject, JsonParseError> {
+    match parse_json_value(input) {
+        Ok((rest, value)) => {
+            if rest.trim().is_empty() {
+                Ok(value)
+            } else {
+                let offset = input.len() - rest.len();
+                let kind = JsonParseErrorKind::UnexpectedToken(
+                    rest.chars().next().expect("non-empty remaining input"),
+                );
+                Err(JsonParseError::at(input, offset, kind))
+            }
+        }
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+            let offset = input.len() - e.input.len();
+            Err(JsonParseError::at(input, offset, e.kind))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(JsonParseError::at(
+            input,
+            input.len(),
+            JsonParseErrorKind::UnexpectedEndOfInput,
+        )),
+    }
+}
+
+/// Escapes a string for embedding in JSON output: quotes and backslashes are
+/// escaped, the common short escapes (`\n`, `\t`, ...) are used where they
+/// apply, and any other control character falls back to `\uXXXX`.
+fn escape_json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// An error produced while parsing a newline-delimited JSON stream,
+/// identifying the 1-based line on which parsing failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NdjsonError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for NdjsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Parses newline-delimited JSON: each non-empty line is parsed independently
+/// as its own complete document with `parse_json`. Blank lines are skipped.
+/// Parsing stops at the first malformed line; the returned error identifies
+/// that 1-based line, and no values from the stream are returned.
+pub fn parse_ndjson(input: &str) -> Result<Vec<JSONObject>, NdjsonError> {
+    let mut values = Vec::new();
+    for (idx, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match parse_json(line) {
+            Ok(value) => values.push(value),
+            Err(e) => {
+                return Err(NdjsonError {
+                    line: idx + 1,
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+    Ok(values)
+}
+
 impl Display for JSONObject {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             JSONObject::Null => write!(f, "null"),
             JSONObject::Bool(b) => write!(f, "{}", *b),
-            JSONObject::Number(n) => write!(f, "{}", n),
-            JSONObject::String(s) => write!(f, "\"{}\"", s),
+            JSONObject::Int(i) => write!(f, "{}", i),
+            JSONObject::UInt(u) => write!(f, "{}", u),
+            JSONObject::Number(n) => write!(f, "{}", format_number(*n)),
+            JSONObject::String(s) => write!(f, "\"{}\"", escape_json_string(s)),
             JSONObject::Array(arr) => {
                 let elements: Vec<String> = arr.iter().map(|v| v.to_string()).collect();
                 write!(f, "[{}]", elements.join(", "))
@@ -176,10 +710,126 @@ impl Display for JSONObject {
             JSONObject::Map(vec) => {
                 let pairs: Vec<String> = vec
                     .iter()
-                    .map(|(k, v)| format!("\"{}\": {}", k, v))
+                    .map(|(k, v)| format!("\"{}\": {}", escape_json_string(k), v))
                     .collect();
                 write!(f, "{{{}}}", pairs.join(", "))
             }
         }
     }
 }
+
+/// Formats a finite float so it always round-trips back through
+/// `number_from_token` as a `Number`: `f64::to_string` drops the `.0` off
+/// whole numbers (e.g. `4.0` becomes `"4"`), which would otherwise re-parse
+/// as an `Int`. Infinity and NaN get no special handling and come out as
+/// `inf.0` / `NaN.0`, which are not valid JSON.
+fn format_number(n: f64) -> String {
+    let s = n.to_string();
+    if s.contains(['.', 'e', 'E']) {
+        s
+    } else {
+        format!("{}.0", s)
+    }
+}
+
+/// Serializes a scalar (everything but `Array`/`Map`) the same way in both
+/// compact and pretty mode, since there is nothing to indent.
+fn write_scalar(value: &JSONObject, out: &mut String) {
+    match value {
+        JSONObject::Null => out.push_str("null"),
+        JSONObject::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        JSONObject::Int(i) => out.push_str(&i.to_string()),
+        JSONObject::UInt(u) => out.push_str(&u.to_string()),
+        JSONObject::Number(n) => out.push_str(&format_number(*n)),
+        JSONObject::String(s) => {
+            out.push('"');
+            out.push_str(&escape_json_string(s));
+            out.push('"');
+        }
+        JSONObject::Array(_) | JSONObject::Map(_) => unreachable!("not a scalar"),
+    }
+}
+
+/// Serializes `value` to the most compact valid JSON: no whitespace at all
+/// between tokens.
+pub fn to_string_compact(value: &JSONObject) -> String {
+    let mut out = String::new();
+    write_compact(value, &mut out);
+    out
+}
+
+fn write_compact(value: &JSONObject, out: &mut String) {
+    match value {
+        JSONObject::Array(arr) => {
+            out.push('[');
+            for (i, v) in arr.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_compact(v, out);
+            }
+            out.push(']');
+        }
+        JSONObject::Map(entries) => {
+            out.push('{');
+            for (i, (k, v)) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push('"');
+                out.push_str(&escape_json_string(k));
+                out.push_str("\":");
+                write_compact(v, out);
+            }
+            out.push('}');
+        }
+        scalar => write_scalar(scalar, out),
+    }
+}
+
+/// Serializes `value` as indented, multi-line JSON: `indent` spaces per
+/// nesting level, a newline after every `{`/`[`, and the closing bracket on
+/// its own line. Empty arrays and objects are written as `[]` and `{}`.
+pub fn to_string_pretty(value: &JSONObject, indent: usize) -> String {
+    let mut out = String::new();
+    write_pretty(value, indent, 0, &mut out);
+    out
+}
+
+fn write_pretty(value: &JSONObject, indent: usize, depth: usize, out: &mut String) {
+    let pad = |level: usize| " ".repeat(indent * level);
+    match value {
+        JSONObject::Array(arr) if arr.is_empty() => out.push_str("[]"),
+        JSONObject::Array(arr) => {
+            out.push_str("[\n");
+            for (i, v) in arr.iter().enumerate() {
+                out.push_str(&pad(depth + 1));
+                write_pretty(v, indent, depth + 1, out);
+                if i + 1 < arr.len() {
+                    out.push(',');
+                }
+                out.push('\n');
+            }
+            out.push_str(&pad(depth));
+            out.push(']');
+        }
+        JSONObject::Map(entries) if entries.is_empty() => out.push_str("{}"),
+        JSONObject::Map(entries) => {
+            out.push_str("{\n");
+            for (i, (k, v)) in entries.iter().enumerate() {
+                out.push_str(&pad(depth + 1));
+                out.push('"');
+                out.push_str(&escape_json_string(k));
+                out.push_str("\": ");
+                write_pretty(v, indent, depth + 1, out);
+                if i + 1 < entries.len() {
+                    out.push(',');
+                }
+                out.push('\n');
+            }
+            out.push_str(&pad(depth));
+            out.push('}');
+        }
+        scalar => write_scalar(scalar, out),
+    }
+}
@@ -4,14 +4,38 @@ use std::env;
 use std::fs;
 use std::io::{self, Read};
 
+const PRETTY_INDENT: usize = 2;
+
+/// How a parsed value should be rendered back to the user.
+#[derive(Clone, Copy)]
+enum OutputMode {
+    Default,
+    Pretty,
+    Compact,
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let input = if args.len() > 1 {
+    let ndjson = args.iter().skip(1).any(|a| a == "--ndjson");
+    let pretty = args.iter().skip(1).any(|a| a == "--pretty");
+    let compact = args.iter().skip(1).any(|a| a == "--compact");
+    let mode = if pretty {
+        OutputMode::Pretty
+    } else if compact {
+        OutputMode::Compact
+    } else {
+        OutputMode::Default
+    };
+    let path = args.iter().skip(1).find(|a| {
+        a.as_str() != "--ndjson" && a.as_str() != "--pretty" && a.as_str() != "--compact"
+    });
+
+    let input = if let Some(path) = path {
         // Read from file
-        match fs::read_to_string(&args[1]) {
+        match fs::read_to_string(path) {
             Ok(content) => content,
             Err(e) => {
-                eprintln!("Error reading file '{}': {}", args[1], e);
+                eprintln!("Error reading file '{}': {}", path, e);
                 std::process::exit(1);
             }
         }
@@ -25,17 +49,40 @@ fn main() {
         buffer
     };
 
-    match json::parse_json_value(&input) {
-        Ok((rest, json_obj)) => {
-            if !rest.trim().is_empty() {
-                eprintln!("Warning: Unparsed input remaining: '{}'", rest);
+    if ndjson {
+        match json::parse_ndjson(&input) {
+            Ok(values) => {
+                println!("Valid NDJSON ({} records):", values.len());
+                for value in &values {
+                    println!("{}", render(value, mode));
+                }
             }
+            Err(e) => {
+                eprintln!("Invalid NDJSON: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    match json::parse_json(&input) {
+        Ok(json_obj) => {
             println!("Valid JSON:");
-            println!("{}", json_obj);
+            println!("{}", render(&json_obj, mode));
         }
         Err(e) => {
-            eprintln!("Invalid JSON: {:?}", e);
+            eprintln!("Invalid JSON: {}", e);
             std::process::exit(1);
         }
     }
 }
+
+/// Renders `value` according to `mode`: `--pretty` indents, `--compact`
+/// strips all whitespace, and the default falls back to `Display`.
+fn render(value: &json::JSONObject, mode: OutputMode) -> String {
+    match mode {
+        OutputMode::Pretty => json::to_string_pretty(value, PRETTY_INDENT),
+        OutputMode::Compact => json::to_string_compact(value),
+        OutputMode::Default => value.to_string(),
+    }
+}
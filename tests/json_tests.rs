@@ -28,18 +28,56 @@ fn test_parse_json_number_valid() {
             "Input '{}' was not parsed correctly. Expected remaining '{}', got '{}'",
             input, expected_remaining, remaining
         );
-        match json_number {
-            JSONObject::Number(n) => assert!(
-                (n - expected_value).abs() < f64::EPSILON,
-                "Parsed value {} does not match expected {}",
-                n,
-                expected_value
-            ),
-            _ => panic!("Parsed value is not a JSONObject::Number"),
-        }
+        let n = match json_number {
+            JSONObject::Int(i) => i as f64,
+            JSONObject::UInt(u) => u as f64,
+            JSONObject::Number(n) => n,
+            other => panic!("Parsed value is not numeric: {:?}", other),
+        };
+        assert!(
+            (n - expected_value).abs() < f64::EPSILON,
+            "Parsed value {} does not match expected {}",
+            n,
+            expected_value
+        );
     }
 }
 
+#[test]
+fn test_parse_json_number_distinguishes_int_and_float() {
+    assert_eq!(parse_json_number("42").unwrap().1, JSONObject::Int(42));
+    assert_eq!(parse_json_number("-7").unwrap().1, JSONObject::Int(-7));
+    assert_eq!(
+        parse_json_number("18446744073709551615").unwrap().1,
+        JSONObject::UInt(u64::MAX)
+    );
+    assert_eq!(
+        parse_json_number("3.14").unwrap().1,
+        JSONObject::Number(3.14)
+    );
+    assert_eq!(
+        parse_json_number("1e6").unwrap().1,
+        JSONObject::Number(1e6)
+    );
+    assert_eq!("42".to_string(), JSONObject::Int(42).to_string());
+    assert_eq!(
+        u64::MAX.to_string(),
+        JSONObject::UInt(u64::MAX).to_string()
+    );
+}
+
+#[test]
+fn test_as_u64_accepts_parsed_int() {
+    assert_eq!(parse_json("42").unwrap().as_u64(), Some(42));
+}
+
+#[test]
+fn test_display_keeps_whole_number_floats_as_numbers() {
+    let value = JSONObject::Number(4.0);
+    assert_eq!(value.to_string(), "4.0");
+    assert_eq!(parse_json(&value.to_string()).unwrap(), value);
+}
+
 #[test]
 fn test_parse_json_number_invalid() {
     let cases = vec!["abc", "--5", "..12", ""];
@@ -59,18 +97,18 @@ fn test_parse_json_number_invalid() {
 fn test_parse_json_string() {
     let cases = vec![
         (r#"simple"#, "simple"),
-        (r#"hello \"world\""#, "hello \\\"world\\\""),
-        (r#"line\nbreak"#, "line\\nbreak"),
-        (r#"tab\tindent"#, "tab\\tindent"),
-        (r#"backslash\\test"#, "backslash\\\\test"),
-        (
-            r#"mix \" of \\ all \n escapes"#,
-            "mix \\\" of \\\\ all \\n escapes",
-        ),
+        (r#"hello \"world\""#, "hello \"world\""),
+        (r#"line\nbreak"#, "line\nbreak"),
+        (r#"tab\tindent"#, "tab\tindent"),
+        (r#"backslash\\test"#, "backslash\\test"),
+        (r#"mix \" of \\ all \n escapes"#, "mix \" of \\ all \n escapes"),
         (
             r#"quote: \" and backslash: \\"#,
-            "quote: \\\" and backslash: \\\\",
+            "quote: \" and backslash: \\",
         ),
+        (r#"slash\/escape"#, "slash/escape"),
+        (r#"hex escape \u0041\u0042"#, "hex escape AB"),
+        (r#"surrogate pair \uD83D\uDE00"#, "surrogate pair \u{1F600}"),
     ];
 
     for (input, expected) in cases {
@@ -97,6 +135,23 @@ fn test_parse_json_string() {
     }
 }
 
+#[test]
+fn test_parse_json_string_invalid_escape() {
+    let cases = vec![
+        r#""lone high surrogate \uD800""#,
+        r#""lone low surrogate \uDC00""#,
+        r#""high surrogate not followed by low \uD800A""#,
+    ];
+
+    for input in cases {
+        assert!(
+            parse_json_string(input).is_err(),
+            "Expected error for '{}'",
+            input
+        );
+    }
+}
+
 #[test]
 fn test_parse_json_null() {
     let result = parse_json_null("null");
@@ -148,9 +203,9 @@ fn test_parse_json_array() {
         (
             "[1, 2, 3]",
             JSONObject::Array(vec![
-                JSONObject::Number(1.0),
-                JSONObject::Number(2.0),
-                JSONObject::Number(3.0),
+                JSONObject::Int(1),
+                JSONObject::Int(2),
+                JSONObject::Int(3),
             ]),
         ),
     ];
@@ -174,7 +229,7 @@ fn test_parse_json_value_all_cases() {
         ("null", JSONObject::Null),
         ("true", JSONObject::Bool(true)),
         ("false", JSONObject::Bool(false)),
-        ("42", JSONObject::Number(42.0)),
+        ("42", JSONObject::Int(42)),
         ("-3.14", JSONObject::Number(-3.14)),
         (r#""hello""#, JSONObject::String("hello".to_string())),
         (
@@ -182,14 +237,14 @@ fn test_parse_json_value_all_cases() {
             JSONObject::Array(vec![
                 JSONObject::Bool(true),
                 JSONObject::Null,
-                JSONObject::Number(5.0),
+                JSONObject::Int(5),
             ]),
         ),
         (
             r#"{"a": 1, "b": false}"#,
             JSONObject::Map({
                 let mut m = Vec::new();
-                m.push(("a".to_string(), JSONObject::Number(1.0)));
+                m.push(("a".to_string(), JSONObject::Int(1)));
                 m.push(("b".to_string(), JSONObject::Bool(false)));
                 m
             }),
@@ -213,3 +268,151 @@ fn test_parse_json_value_all_cases() {
         );
     }
 }
+
+#[test]
+fn test_parse_ndjson_valid() {
+    let input = "{\"a\": 1}\n\ntrue\n   \nnull\n[1, 2]\n";
+    let result = parse_ndjson(input);
+    assert!(result.is_ok(), "Parsing ndjson failed: {:?}", result);
+    let values = result.unwrap();
+    assert_eq!(
+        values,
+        vec![
+            JSONObject::Map(vec![("a".to_string(), JSONObject::Int(1))]),
+            JSONObject::Bool(true),
+            JSONObject::Null,
+            JSONObject::Array(vec![JSONObject::Int(1), JSONObject::Int(2)]),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_ndjson_reports_failing_line() {
+    let input = "true\nfalse\nnot json\nnull\n";
+    let result = parse_ndjson(input);
+    assert!(result.is_err(), "Expected ndjson parsing to fail");
+    assert_eq!(result.unwrap_err().line, 3);
+}
+
+#[test]
+fn test_parse_json_error_reports_line_and_column() {
+    let input = "{\n  \"a\": 1,\n  \"b\" 2\n}";
+    let result = parse_json(input);
+    assert!(result.is_err(), "Expected a parse error for '{}'", input);
+    let err = result.unwrap_err();
+    assert_eq!(err.line, 3);
+    assert_eq!(err.column, 7);
+    assert_eq!(err.kind, JsonParseErrorKind::ExpectedToken(':'));
+}
+
+#[test]
+fn test_parse_json_error_unexpected_end_of_input() {
+    let result = parse_json("");
+    assert!(result.is_err(), "Expected a parse error for empty input");
+    assert_eq!(
+        result.unwrap_err().kind,
+        JsonParseErrorKind::UnexpectedEndOfInput
+    );
+}
+
+#[test]
+fn test_json_object_accessors() {
+    let map = JSONObject::Map(vec![
+        ("name".to_string(), JSONObject::String("ferris".to_string())),
+        ("age".to_string(), JSONObject::Number(3.0)),
+        ("active".to_string(), JSONObject::Bool(true)),
+        (
+            "tags".to_string(),
+            JSONObject::Array(vec![JSONObject::Number(1.0), JSONObject::Number(2.0)]),
+        ),
+        ("extra".to_string(), JSONObject::Null),
+        ("tags".to_string(), JSONObject::Null),
+    ]);
+
+    assert_eq!(map.get("name").and_then(JSONObject::as_str), Some("ferris"));
+    assert_eq!(map.get("age").and_then(JSONObject::as_f64), Some(3.0));
+    assert_eq!(map.get("active").and_then(JSONObject::as_bool), Some(true));
+    assert_eq!(JSONObject::Int(-5).as_i64(), Some(-5));
+    assert_eq!(JSONObject::UInt(5).as_u64(), Some(5));
+    assert_eq!(JSONObject::Int(5).as_u64(), Some(5));
+    assert_eq!(JSONObject::Int(-5).as_u64(), None);
+    assert_eq!(JSONObject::Int(-5).as_f64(), Some(-5.0));
+    assert_eq!(JSONObject::UInt(5).as_f64(), Some(5.0));
+    assert!(map.get("extra").is_some_and(JSONObject::is_null));
+    assert!(map.get("missing").is_none());
+
+    let tags = map.get("tags").and_then(JSONObject::as_array).unwrap();
+    assert_eq!(tags, &[JSONObject::Number(1.0), JSONObject::Number(2.0)]);
+    assert_eq!(map.get_all("tags").len(), 2);
+
+    let array = JSONObject::Array(vec![JSONObject::Bool(false), JSONObject::Null]);
+    assert_eq!(array.index(0), Some(&JSONObject::Bool(false)));
+    assert_eq!(array.index(5), None);
+}
+
+#[test]
+fn test_json_object_try_from_conversions() {
+    assert_eq!(f64::try_from(JSONObject::Number(4.5)), Ok(4.5));
+    assert_eq!(f64::try_from(JSONObject::Int(-5)), Ok(-5.0));
+    assert_eq!(f64::try_from(JSONObject::UInt(5)), Ok(5.0));
+    assert!(f64::try_from(JSONObject::Null).is_err());
+
+    assert_eq!(i64::try_from(JSONObject::Int(-5)), Ok(-5));
+    assert!(i64::try_from(JSONObject::Null).is_err());
+
+    assert_eq!(u64::try_from(JSONObject::UInt(u64::MAX)), Ok(u64::MAX));
+    assert_eq!(u64::try_from(JSONObject::Int(5)), Ok(5));
+    assert!(u64::try_from(JSONObject::Int(-5)).is_err());
+    assert!(u64::try_from(JSONObject::Null).is_err());
+
+    assert_eq!(
+        String::try_from(JSONObject::String("hi".to_string())),
+        Ok("hi".to_string())
+    );
+    assert!(String::try_from(JSONObject::Bool(true)).is_err());
+
+    assert_eq!(bool::try_from(JSONObject::Bool(true)), Ok(true));
+    assert!(bool::try_from(JSONObject::Number(1.0)).is_err());
+
+    assert_eq!(
+        Vec::<JSONObject>::try_from(JSONObject::Array(vec![JSONObject::Null])),
+        Ok(vec![JSONObject::Null])
+    );
+    assert!(Vec::<JSONObject>::try_from(JSONObject::Null).is_err());
+}
+
+#[test]
+fn test_to_string_compact() {
+    let value = JSONObject::Map(vec![
+        ("a".to_string(), JSONObject::Int(1)),
+        (
+            "b".to_string(),
+            JSONObject::Array(vec![JSONObject::Null, JSONObject::Bool(true)]),
+        ),
+        ("c".to_string(), JSONObject::String("x\ny".to_string())),
+    ]);
+
+    assert_eq!(
+        to_string_compact(&value),
+        r#"{"a":1,"b":[null,true],"c":"x\ny"}"#
+    );
+    assert_eq!(to_string_compact(&JSONObject::Array(vec![])), "[]");
+    assert_eq!(to_string_compact(&JSONObject::Map(vec![])), "{}");
+}
+
+#[test]
+fn test_to_string_pretty() {
+    let value = JSONObject::Map(vec![
+        ("a".to_string(), JSONObject::Int(1)),
+        (
+            "b".to_string(),
+            JSONObject::Array(vec![JSONObject::Int(1), JSONObject::Int(2)]),
+        ),
+    ]);
+
+    let expected = "{\n  \"a\": 1,\n  \"b\": [\n    1,\n    2\n  ]\n}";
+    assert_eq!(to_string_pretty(&value, 2), expected);
+
+    assert_eq!(to_string_pretty(&JSONObject::Array(vec![]), 2), "[]");
+    assert_eq!(to_string_pretty(&JSONObject::Null, 2), "null");
+}
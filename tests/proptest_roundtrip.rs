@@ -0,0 +1,96 @@
+use json_rs::json::{parse_json_value, to_string_compact, to_string_pretty, JSONObject};
+use proptest::prelude::*;
+
+/// Every escape class `parse_escaped_char` understands, plus characters that
+/// force the `\uXXXX` fallback and a surrogate-pair emoji.
+fn arb_escape_heavy_string() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            Just('"'),
+            Just('\\'),
+            Just('/'),
+            Just('\u{8}'),
+            Just('\u{c}'),
+            Just('\n'),
+            Just('\r'),
+            Just('\t'),
+            Just('\u{0}'),
+            Just('\u{1f}'),
+            Just('\u{1F600}'),
+            any::<char>(),
+        ],
+        0..16,
+    )
+    .prop_map(|chars| chars.into_iter().collect())
+}
+
+/// Numbers at the edges of the ranges `number_from_token` switches between:
+/// `i64`, `u64` and `f64`.
+fn arb_edge_number() -> impl Strategy<Value = JSONObject> {
+    prop_oneof![
+        Just(JSONObject::Int(i64::MIN)),
+        Just(JSONObject::Int(i64::MAX)),
+        Just(JSONObject::Int(0)),
+        Just(JSONObject::UInt(u64::MAX)),
+        Just(JSONObject::UInt(i64::MAX as u64 + 1)),
+        Just(JSONObject::Number(f64::MIN)),
+        Just(JSONObject::Number(f64::MAX)),
+        Just(JSONObject::Number(f64::MIN_POSITIVE)),
+        Just(JSONObject::Number(0.0)),
+    ]
+}
+
+fn arb_leaf() -> impl Strategy<Value = JSONObject> {
+    prop_oneof![
+        Just(JSONObject::Null),
+        any::<bool>().prop_map(JSONObject::Bool),
+        any::<i64>().prop_map(JSONObject::Int),
+        // `number_from_token` tries `i64` before `u64`, so only values above
+        // `i64::MAX` are ever reachable as `UInt` by re-parsing serialized
+        // output; anything in range 0..=i64::MAX round-trips as `Int`.
+        (i64::MAX as u64 + 1..=u64::MAX).prop_map(JSONObject::UInt),
+        any::<f64>()
+            .prop_filter("finite", |n| n.is_finite())
+            .prop_map(JSONObject::Number),
+        "\\PC*".prop_map(JSONObject::String),
+        arb_escape_heavy_string().prop_map(JSONObject::String),
+        arb_edge_number(),
+    ]
+}
+
+/// Arbitrary `JSONObject` trees, nested up to 4 levels deep with at most 7
+/// elements per `Array`/`Map`.
+fn arb_json_object() -> impl Strategy<Value = JSONObject> {
+    arb_leaf().prop_recursive(4, 64, 8, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..8).prop_map(JSONObject::Array),
+            prop::collection::vec(("\\PC*", inner), 0..8).prop_map(JSONObject::Map),
+        ]
+    })
+}
+
+proptest! {
+    /// `parse_json_value` must never panic, valid JSON or not.
+    #[test]
+    fn parse_json_value_never_panics(input in "\\PC*") {
+        let _ = parse_json_value(&input);
+    }
+
+    /// compact serialize -> parse is a fixpoint.
+    #[test]
+    fn compact_round_trips(value in arb_json_object()) {
+        let rendered = to_string_compact(&value);
+        let (remaining, parsed) = parse_json_value(&rendered).expect("serialized output must parse");
+        prop_assert!(remaining.is_empty());
+        prop_assert_eq!(parsed, value);
+    }
+
+    /// pretty serialize -> parse is a fixpoint, for a handful of indent widths.
+    #[test]
+    fn pretty_round_trips(value in arb_json_object(), indent in 0usize..6) {
+        let rendered = to_string_pretty(&value, indent);
+        let (remaining, parsed) = parse_json_value(&rendered).expect("serialized output must parse");
+        prop_assert!(remaining.is_empty());
+        prop_assert_eq!(parsed, value);
+    }
+}